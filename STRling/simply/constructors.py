@@ -1,5 +1,5 @@
 
-from .pattern import STRlingError, Pattern, lit
+from .pattern import STRlingError, STRlingWarning, Pattern, lit, _check_patterns, _is_zero_width, _zero_width_problem
 
 
 
@@ -76,13 +76,15 @@ def any_of(*patterns):
         raise STRlingError(message)
 
     sub_names = named_group_counts.keys()
+    sub_groups = [group for p in clean_patterns for group in p.groups]
+    zero_width = _is_zero_width(clean_patterns)
 
     joined = '|'.join(str(p) for p in clean_patterns)
     new_pattern = f'(?:{joined})'
 
-    return Pattern(new_pattern, composite=True, named_groups=sub_names)
+    return Pattern(new_pattern, composite=True, named_groups=sub_names, zero_width=zero_width, groups=sub_groups)
 
-def may(*patterns):
+def may(*patterns, strict: bool = True):
     """
     Optionally matches the provided patterns. If this pattern is absent, surrounding patterns can still match.
 
@@ -95,6 +97,7 @@ def may(*patterns):
 
     Parameters:
     - *patterns (Pattern/str): One or more patterns to be optionally matched.
+    - strict (optional): Anchors and lookarounds cannot be optional, pass False to only warn with a STRlingWarning.
 
     Returns:
     - Pattern: A Pattern object representing the optional match of the given patterns.
@@ -143,13 +146,91 @@ def may(*patterns):
         """
         raise STRlingError(message)
 
+    # Anchors and lookarounds match a position, so making them optional is meaningless
+    if _is_zero_width(clean_patterns):
+        message = """
+        Anchors and lookarounds cannot be optional as they match a position, not characters.
+
+        Make the characters around them optional instead, or remove the `simply.may()`.
+
+        To keep it optional anyway, pass `strict=False` to only get a warning.
+        """
+        _zero_width_problem('simply.may(*patterns)', message, strict)
+
     sub_names = named_group_counts.keys()
+    sub_groups = [group for p in clean_patterns for group in p.groups]
 
     joined = merge(*clean_patterns)
     new_pattern = f'{joined}?'
 
-    return Pattern(new_pattern, composite=True, named_groups=sub_names)
+    return Pattern(new_pattern, composite=True, named_groups=sub_names, groups=sub_groups)
+
+def maybe_one(*patterns):
+    """
+    Optionally matches the provided patterns at most once, exactly like `simply.may(*patterns)`.
+
+    Example: simply as s
+        - Matches any letter, along with any trailing digit.
+
+        pattern = s.merge(s.letter(), s.maybe_one(s.digit()))
+
+    Parameters:
+    - *patterns (Pattern/str): One or more patterns to be optionally matched.
+
+    Returns:
+    - Pattern: A Pattern object representing the optional match of the given patterns.
+    """
+    clean_patterns = _check_patterns('simply.maybe_one(*patterns)', patterns)
+
+    return may(*clean_patterns)
+
+def maybe_many(*patterns):
+    """
+    Matches the provided patterns zero or more times.
+
+    - Named groups CANNOT be repeated, so they are not allowed here.
+
+    Example: simply as s
+        - Matches a letter followed by any number of digits, including none.
+
+        pattern = s.merge(s.letter(), s.maybe_many(s.digit()))
+
+        In the text, "A B2 C345" the pattern above matches 'A', 'B2' and 'C345'.
+
+    Parameters:
+    - *patterns (Pattern/str): One or more patterns to be matched zero or more times.
+
+    Returns:
+    - Pattern: A Pattern object representing zero or more repetitions of the given patterns.
+    """
+
+    clean_patterns = _check_patterns('simply.maybe_many(*patterns)', patterns)
+
+    return merge(*clean_patterns)(0, 0)
 
+def some(*patterns):
+    """
+    Matches the provided patterns one or more times.
+
+    - Named groups CANNOT be repeated, so they are not allowed here.
+
+    Example: simply as s
+        - Matches a letter followed by at least one digit.
+
+        pattern = s.merge(s.letter(), s.some(s.digit()))
+
+        In the text, "A B2 C345" the pattern above matches 'B2' and 'C345'.
+
+    Parameters:
+    - *patterns (Pattern/str): One or more patterns to be matched one or more times.
+
+    Returns:
+    - Pattern: A Pattern object representing one or more repetitions of the given patterns.
+    """
+
+    clean_patterns = _check_patterns('simply.some(*patterns)', patterns)
+
+    return merge(*clean_patterns)(1, 0)
 
 
 def merge(*patterns):
@@ -212,11 +293,13 @@ def merge(*patterns):
         raise STRlingError(message)
 
     sub_names = named_group_counts.keys()
+    sub_groups = [group for p in clean_patterns for group in p.groups]
+    zero_width = _is_zero_width(clean_patterns)
 
     joined = ''.join(str(p) for p in clean_patterns)
     new_pattern = f'(?:{joined})'
 
-    return Pattern(new_pattern, composite=True, named_groups=sub_names)
+    return Pattern(new_pattern, composite=True, named_groups=sub_names, zero_width=zero_width, groups=sub_groups)
 
 def capture(*patterns):
     """
@@ -309,11 +392,56 @@ def capture(*patterns):
         raise STRlingError(message)
 
     sub_names = named_group_counts.keys()
+    sub_groups = [group for p in clean_patterns for group in p.groups]
+    zero_width = _is_zero_width(clean_patterns)
 
     joined = ''.join(str(p) for p in clean_patterns)
     new_pattern = f'({joined})'
 
-    return Pattern(new_pattern, composite=True, numbered_group=True, named_groups=sub_names)
+    return Pattern(new_pattern, composite=True, numbered_group=True, named_groups=sub_names, zero_width=zero_width, groups=[None, *sub_groups])
+
+def capture_if(condition, *patterns):
+    """
+    Creates a numbered group like `simply.capture()` only when the condition is True,
+    otherwise combines the patterns without capturing like `simply.merge()`.
+
+    - Every numbered group costs the RegEx engine extra work and shifts the index of later groups,
+    so skip captures you won't reference to keep large patterns fast and their indexes predictable.
+
+    - The result follows the range rules of whichever it becomes.
+    When True, like captures, it CANNOT be invoked with a range but CAN be invoked with a number of copies.
+
+    s.capture_if(True, s.digit())(1, 2) <== INVALID
+    s.capture_if(False, s.digit())(1, 2) <== VALID
+    s.capture_if(True, s.digit())(3) <== VALID
+
+    Example: simply as s
+        - Captures the digits only when the caller needs them later.
+
+        need_digits = False
+        my_pattern = s.capture_if(need_digits, s.digit(3))  # Same as s.merge(s.digit(3))
+
+    Parameters:
+    - condition (bool): Whether the patterns should be captured.
+    - *patterns (Pattern/str): One or more patterns to be conditionally captured.
+
+    Returns:
+    - Pattern: A Pattern object representing the capturing group when `condition` is True,
+    otherwise the non-capturing group of the given patterns.
+    """
+
+    if not isinstance(condition, bool):
+        message = """
+        Method: simply.capture_if(condition, *patterns)
+
+        The `condition` parameter must be a boolean (True or False).
+        """
+        raise STRlingError(message)
+
+    if condition:
+        return capture(*patterns)
+
+    return merge(*patterns)
 
 def group(name, *patterns):
     """
@@ -414,8 +542,10 @@ def group(name, *patterns):
         raise STRlingError(message)
 
     sub_names = named_group_counts.keys()
+    sub_groups = [group for p in clean_patterns for group in p.groups]
+    zero_width = _is_zero_width(clean_patterns)
 
     joined = ''.join(str(p) for p in clean_patterns)
     new_pattern = f'(?P<{name}>{joined})'
 
-    return Pattern(new_pattern, composite=True, named_groups=[name, *sub_names])
+    return Pattern(new_pattern, composite=True, named_groups=[name, *sub_names], zero_width=zero_width, groups=[name, *sub_groups])
@@ -1,5 +1,5 @@
 
-from .pattern import STRlingError, Pattern, lit
+from .pattern import STRlingError, Pattern, lit, _check_pattern
 
 
 
@@ -37,7 +37,7 @@ def ahead(pattern):
         """
         raise STRlingError(message)
 
-    return Pattern(f'(?={pattern})', composite=True)
+    return Pattern(f'(?={pattern})', composite=True, named_groups=pattern.named_groups, zero_width=True, groups=pattern.groups)
 
 def not_ahead(pattern):
     """
@@ -68,7 +68,7 @@ def not_ahead(pattern):
         """
         raise STRlingError(message)
 
-    return Pattern(f'(?!{pattern})', composite=True)
+    return Pattern(f'(?!{pattern})', composite=True, named_groups=pattern.named_groups, zero_width=True, groups=pattern.groups)
 
 def behind(pattern):
     """
@@ -99,7 +99,7 @@ def behind(pattern):
         """
         raise STRlingError(message)
 
-    return Pattern(f'(?<={pattern})', composite=True)
+    return Pattern(f'(?<={pattern})', composite=True, named_groups=pattern.named_groups, zero_width=True, groups=pattern.groups)
 
 def not_behind(pattern):
     """
@@ -130,4 +130,91 @@ def not_behind(pattern):
         """
         raise STRlingError(message)
 
-    return Pattern(f'(?<!{pattern})', composite=True)
+    return Pattern(f'(?<!{pattern})', composite=True, named_groups=pattern.named_groups, zero_width=True, groups=pattern.groups)
+
+
+
+############################
+# Lookaround Aliases
+########
+
+
+def followed_by(pattern):
+    """
+    Only matches the rest of a pattern if the provided pattern comes next, without including it in the result.
+    Works exactly like `simply.ahead(pattern)`.
+
+    Example: simply as s
+        - Only matches a digit followed by a letter.
+
+        my_pattern = s.merge(s.digit(), s.followed_by(s.letter()))
+
+    Parameters:
+    - pattern (Pattern/str): The pattern that must come next.
+
+    Returns:
+    - Pattern: A Pattern object representing the positive lookahead.
+    """
+    pattern = _check_pattern('simply.followed_by(pattern)', pattern)
+
+    return ahead(pattern)
+
+def not_followed_by(pattern):
+    """
+    Only matches the rest of a pattern if the provided pattern does not come next.
+    Works exactly like `simply.not_ahead(pattern)`.
+
+    Example: simply as s
+        - Only matches a digit if not followed by a letter.
+
+        my_pattern = s.merge(s.digit(), s.not_followed_by(s.letter()))
+
+    Parameters:
+    - pattern (Pattern/str): The pattern that must not come next.
+
+    Returns:
+    - Pattern: A Pattern object representing the negative lookahead.
+    """
+    pattern = _check_pattern('simply.not_followed_by(pattern)', pattern)
+
+    return not_ahead(pattern)
+
+def preceded_by(pattern):
+    """
+    Only matches the rest of a pattern if the provided pattern comes right before it, without including it in the result.
+    Works exactly like `simply.behind(pattern)`.
+
+    Example: simply as s
+        - Only matches a letter preceded by a digit.
+
+        my_pattern = s.merge(s.preceded_by(s.digit()), s.letter())
+
+    Parameters:
+    - pattern (Pattern/str): The pattern that must come right before.
+
+    Returns:
+    - Pattern: A Pattern object representing the positive lookbehind.
+    """
+    pattern = _check_pattern('simply.preceded_by(pattern)', pattern)
+
+    return behind(pattern)
+
+def not_preceded_by(pattern):
+    """
+    Only matches the rest of a pattern if the provided pattern does not come right before it.
+    Works exactly like `simply.not_behind(pattern)`.
+
+    Example: simply as s
+        - Only matches a letter if not preceded by a digit.
+
+        my_pattern = s.merge(s.not_preceded_by(s.digit()), s.letter())
+
+    Parameters:
+    - pattern (Pattern/str): The pattern that must not come right before.
+
+    Returns:
+    - Pattern: A Pattern object representing the negative lookbehind.
+    """
+    pattern = _check_pattern('simply.not_preceded_by(pattern)', pattern)
+
+    return not_behind(pattern)
@@ -1,5 +1,5 @@
 
-import re, textwrap
+import re, textwrap, warnings
 
 
 
@@ -15,10 +15,83 @@ class STRlingError(ValueError):
     def __str__(self):
         return f"\n\nSTRlingError: Invalid Pattern Attempted.\n\n\t{self.message}"
 
+class STRlingWarning(UserWarning):
+    """
+    Warns about a pattern that is valid but almost certainly a mistake, raised instead of a STRlingError when `strict=False`.
+    """
+
 def lit(text):
     escaped_text = re.escape(text).replace('/', '\/')
     return Pattern(escaped_text)
 
+def _check_pattern(method: str, pattern):
+    """
+    Converts a `str` into a literal Pattern and raises an error naming `method` if the parameter is not a Pattern.
+    """
+    if isinstance(pattern, str):
+        pattern = lit(pattern)
+
+    if not isinstance(pattern, Pattern):
+        message = f"""
+        Method: {method}
+
+        The parameter must be an instance of `Pattern` or `str`.
+
+        Use a string such as "123abc$" to match literal characters, or use a predefined set like `simply.letter()`.
+        """
+        raise STRlingError(message)
+
+    return pattern
+
+def _check_patterns(method: str, patterns):
+    """
+    Converts each `str` into a literal Pattern and raises an error naming `method`
+    if no patterns are given or any parameter is not a Pattern.
+    """
+    if not patterns:
+        message = f"""
+        Method: {method}
+
+        At least one pattern must be provided.
+
+        Use a string such as "123abc$" to match literal characters, or use a predefined set like `simply.letter()`.
+        """
+        raise STRlingError(message)
+
+    clean_patterns = []
+    for pattern in patterns:
+        if isinstance(pattern, str):
+            pattern = lit(pattern)
+
+        if not isinstance(pattern, Pattern):
+            message = f"""
+            Method: {method}
+
+            The parameters must be instances of `Pattern` or `str`.
+
+            Use a string such as "123abc$" to match literal characters, or use a predefined set like `simply.letter()`.
+            """
+            raise STRlingError(message)
+
+        clean_patterns.append(pattern)
+
+    return clean_patterns
+
+def _is_zero_width(patterns):
+    """
+    Returns True if the patterns match only a position, counting empty literals as zero-width.
+    """
+    return any(p.zero_width for p in patterns) and all(p.zero_width or not p.pattern for p in patterns)
+
+def _zero_width_problem(method: str, message: str, strict: bool):
+    """
+    Raises a STRlingError naming `method`, or only warns with a STRlingWarning when `strict` is False.
+    """
+    if strict:
+        raise STRlingError(f"Method: {method}\n" + textwrap.dedent(message))
+
+    warnings.warn(f"{method}: " + textwrap.dedent(message).strip(), STRlingWarning, stacklevel=3)
+
 def repeat(min_rep: int = None, max_rep: int = None):
     if min_rep is not None and max_rep is not None:
         if max_rep == 0:  # Special case to handle the 'min_rep,' syntax
@@ -47,13 +120,16 @@ class Pattern:
         - custom_set (bool): Indicates if the pattern is a custom character set.
         - composite (bool): Indicates if the pattern is a composite pattern.
         - repeatable (bool): Indicates if the pattern can be repeated.
+        - zero_width (bool): Indicates if the pattern matches a position rather than characters.
+        - groups (list): The groups in left to right order, a name for each named group and None for each numbered group.
 
     Methods:
         - __call__(min_rep=None, max_rep=None): Returns a new Pattern object with the repetition pattern applied.
         - __str__(): Returns the pattern as a string.
         - __add__(other): Allows addition of two Pattern objects.
+        - group_index(name): Returns the number of the named group.
     """
-    def __init__(self, pattern: str, custom_set: bool = False, negated: bool = False, composite: bool = False, named_groups: list = [], numbered_group: bool = False):
+    def __init__(self, pattern: str, custom_set: bool = False, negated: bool = False, composite: bool = False, named_groups: list = None, numbered_group: bool = False, zero_width: bool = False, groups: list = None):
         # The regex pattern string for this instance.
         self.pattern = pattern
         # A custom set is regex with brackets [a-z]
@@ -63,11 +139,15 @@ class Pattern:
         # A composite pattern is one enclosed in parenthesis.
         self.composite = composite
         # A pattern with named_groups cannot repeat.
-        self.named_groups = named_groups
+        self.named_groups = list(named_groups or [])
         # A numbered_group is one that is copied rather than repeated
         self.numbered_group = numbered_group
+        # A zero_width pattern matches a position rather than characters and cannot repeat.
+        self.zero_width = zero_width
+        # The groups in left to right order, a name for each named group and None for each numbered group.
+        self.groups = list(groups or [])
 
-    def __call__(self, min_rep: int = None, max_rep: int = None):
+    def __call__(self, min_rep: int = None, max_rep: int = None, strict: bool = True):
         """
         Applies a repetition pattern to the current pattern.
 
@@ -79,6 +159,10 @@ class Pattern:
         - If only `min_rep` is specified, it represents the exact number of characters to match.
         - If `max_rep` is 0, it means there is no upper limit.
 
+        Zero-Width Patterns:
+        - Anchors and lookarounds match a position, so giving them a range raises a STRlingError.
+        - Pass `strict=False` to only warn with a STRlingWarning and apply the range anyway.
+
         Returns:
         - A new Pattern object with the repetition pattern applied.
         """
@@ -104,6 +188,26 @@ class Pattern:
             """
             raise STRlingError(message)
 
+        # Anchors and lookarounds match a position, so repeating them is meaningless
+        # A numbered group is copied rather than repeated, so its copies are still valid groups
+        if self.zero_width and not self.numbered_group:
+            message = """
+            Anchors and lookarounds cannot be repeated as they match a position, not characters.
+
+            Examples of invalid syntax:
+                simply.start()(1, 2) # a start anchor cannot repeat
+                simply.ahead(simply.digit())(3) # a lookahead cannot repeat
+
+            Remove the range, or move it onto the pattern inside the lookaround.
+
+            Examples of valid syntax:
+                simply.start()
+                simply.ahead(simply.digit(3))
+
+            To keep the range anyway, pass `strict=False` to only get a warning.
+            """
+            _zero_width_problem('Pattern.__call__(min_rep, max_rep)', message, strict)
+
         # Named group is unique and not repeatable
         if self.named_groups and min_rep is not None and max_rep is not None:
             message = """
@@ -152,12 +256,14 @@ class Pattern:
                 raise STRlingError(message)
             else:
                 new_pattern = f'(?:{self.pattern * min_rep})'
+                new_groups = self.groups * min_rep
         # Regular Case: Add range syntax.
         else:
             new_pattern = self.pattern + repeat(min_rep, max_rep)
+            new_groups = self.groups
 
         # Return new instance with updated pattern
-        return self.create_modified_instance(new_pattern)
+        return self.create_modified_instance(new_pattern, named_groups=self.named_groups, zero_width=self.zero_width, groups=new_groups)
 
     def __str__(self):
         """
@@ -165,6 +271,37 @@ class Pattern:
         """
         return self.pattern
 
+    def group_index(self, name: str):
+        """
+        Returns the number of a named group, for engines or libraries that only return numbered groups.
+
+        Groups are numbered from left to right, counting both named groups and numbered groups (captures).
+
+        Example: simply as s
+            pattern = s.merge(s.capture('a'), s.group('x', 'b'), s.capture('c'))
+
+            pattern.group_index('x')  # Returns 2
+
+        Parameters:
+        - name (str): The name given to `simply.group(name, *patterns)`.
+
+        Returns:
+        - int: The number of the named group, or None if the pattern has no group with that name.
+        """
+        if not isinstance(name, str):
+            message = """
+            Method: Pattern.group_index(name)
+
+            The `name` parameter must be a string like 'group_name'.
+            """
+            raise STRlingError(message)
+
+        for index, group_name in enumerate(self.groups, start=1):
+            if group_name == name:
+                return index
+
+        return None
+
     @classmethod
     def create_modified_instance(cls, new_pattern, **kwargs):
         """
@@ -417,38 +417,32 @@ def not_carriage(min_rep: int = None, max_rep: int = None):
 
 def bound(min_rep: int = None, max_rep: int = None):
     """
-    Matches a boundary character.
+    Matches a word boundary, the position between a word character and a non-word character.
 
     Parameters: (min_rep/exact_rep, max_rep)
-    - min_rep (optional): Specifies the minimum number of characters to match.
-    - max_rep (optional): Specifies the maximum number of characters to match.
-
-    Special Cases:
-    - If only `min_rep` is specified, it represents the exact number of characters to match.
-    - If `max_rep` is 0, it means there is no upper limit.
+    - These exist only so the signature stays the same. A boundary is a position and cannot repeat,
+    so every range is rejected with a STRlingError. Use `simply.bound()(min_rep, max_rep, strict=False)`
+    to only get a warning instead.
 
     Returns:
     - An instance of the Pattern class.
     """
-    return Pattern(r'\b')(min_rep, max_rep)
+    return Pattern(r'\b', zero_width=True)(min_rep, max_rep)
 
 
 def not_bound(min_rep: int = None, max_rep: int = None):
     """
-    Matches any character that is not a boundary.
+    Matches any position that is not a word boundary.
 
     Parameters: (min_rep/exact_rep, max_rep)
-    - min_rep (optional): Specifies the minimum number of characters to match.
-    - max_rep (optional): Specifies the maximum number of characters to match.
-
-    Special Cases:
-    - If only `min_rep` is specified, it represents the exact number of characters to match.
-    - If `max_rep` is 0, it means there is no upper limit.
+    - These exist only so the signature stays the same. A boundary is a position and cannot repeat,
+    so every range is rejected with a STRlingError. Use `simply.bound()(min_rep, max_rep, strict=False)`
+    to only get a warning instead.
 
     Returns:
     - An instance of the Pattern class.
     """
-    return Pattern(r'\B')(min_rep, max_rep)
+    return Pattern(r'\B', zero_width=True)(min_rep, max_rep)
 
 
 def start():
@@ -464,7 +458,7 @@ def start():
     Note: There is no `simply.not_start()` function,
     to do this, use `simply.not_behind(simply.start())`.
     """
-    return Pattern(r'^')
+    return Pattern(r'^', zero_width=True)
 
 
 def end():
@@ -480,4 +474,4 @@ def end():
     Note: There is no `simply.not_end()` function,
     to do this, use `simply.not_ahead(simply.end())`.
     """
-    return Pattern(r'$')
+    return Pattern(r'$', zero_width=True)
@@ -0,0 +1,171 @@
+import re, unittest
+
+from STRling import simply as s
+
+
+
+############################
+# Zero Width
+########
+
+
+class TestZeroWidthRanges(unittest.TestCase):
+    def test_anchors_and_lookarounds_reject_ranges(self):
+        for make in [lambda: s.start()(0, 0), lambda: s.end()(1),
+                     lambda: s.bound(1), lambda: s.not_bound()(1, 2),
+                     lambda: s.ahead(s.digit())(3), lambda: s.not_behind('a')(2)]:
+            with self.assertRaises(s.STRlingError):
+                make()
+
+    def test_constructors_pass_on_zero_width(self):
+        for make in [lambda: s.merge(s.start())(1, 2), lambda: s.some(s.bound()),
+                     lambda: s.maybe_many(s.ahead('x')), lambda: s.group('x', s.end())(1, 2),
+                     lambda: s.any_of(s.start(), s.end())(2), lambda: s.may(s.start()),
+                     lambda: s.capture(s.end())(3)(2)]:
+            with self.assertRaises(s.STRlingError):
+                make()
+
+    def test_empty_literals_count_as_zero_width(self):
+        for make in [lambda: s.may(s.bound(), ''), lambda: s.merge(s.start(), '')(2),
+                     lambda: s.any_of(s.start(), '')(2)]:
+            with self.assertRaises(s.STRlingError):
+                make()
+
+    def test_copied_captures_are_not_quantified(self):
+        pattern = s.capture(s.end())(3)
+        self.assertEqual(str(pattern), '(?:($)($)($))')
+        self.assertEqual(re.compile(str(pattern)).groups, 3)
+
+    def test_lenient_mode_warns(self):
+        with self.assertWarns(s.STRlingWarning):
+            self.assertEqual(str(s.merge(s.start())(2, strict=False)), '(?:^){2}')
+        with self.assertWarns(s.STRlingWarning):
+            self.assertEqual(str(s.ahead('a')(2, strict=False)), '(?=a){2}')
+        with self.assertWarns(s.STRlingWarning):
+            self.assertEqual(str(s.may(s.start(), strict=False)), '(?:^)?')
+
+    def test_consuming_patterns_still_repeat(self):
+        self.assertEqual(str(s.bound()), r'\b')
+        self.assertEqual(str(s.merge(s.start(), 'a')(2)), '(?:^a){2}')
+        self.assertEqual(str(s.may('a', s.end())), '(?:a$)?')
+        self.assertEqual(str(s.digit(2)), r'\d{2}')
+
+
+
+############################
+# Constructors
+########
+
+
+class TestQuantifierAliases(unittest.TestCase):
+    def test_maybe_one_matches_may(self):
+        self.assertEqual(str(s.maybe_one('x')), str(s.may('x')))
+
+    def test_maybe_many_is_zero_or_more(self):
+        self.assertEqual(str(s.maybe_many(s.digit())), r'(?:\d){0,}')
+        pattern = s.merge(s.letter(), s.maybe_many(s.digit()))
+        self.assertEqual(re.findall(str(pattern), 'A B2 C345'), ['A', 'B2', 'C345'])
+
+    def test_some_is_one_or_more(self):
+        self.assertEqual(str(s.some(s.digit())), r'(?:\d){1,}')
+        pattern = s.merge(s.letter(), s.some(s.digit()))
+        self.assertEqual(re.findall(str(pattern), 'A B2 C345'), ['B2', 'C345'])
+
+    def test_empty_patterns_rejected(self):
+        for alias in [s.maybe_one, s.maybe_many, s.some]:
+            with self.assertRaisesRegex(s.STRlingError, f'simply.{alias.__name__}'):
+                alias()
+
+    def test_invalid_parameter_names_alias(self):
+        for alias in [s.maybe_one, s.maybe_many, s.some]:
+            with self.assertRaisesRegex(s.STRlingError, f'simply.{alias.__name__}'):
+                alias('a', 3)
+
+    def test_named_groups_rejected(self):
+        with self.assertRaises(s.STRlingError):
+            s.maybe_many(s.group('x', 'a'))
+        with self.assertRaises(s.STRlingError):
+            s.some(s.group('x', 'a'))
+
+
+class TestCaptureIf(unittest.TestCase):
+    def test_false_is_non_capturing(self):
+        pattern = s.capture_if(False, 'a')
+        self.assertEqual(str(pattern), '(?:a)')
+        self.assertEqual(re.compile(str(pattern)).groups, 0)
+
+    def test_true_is_capturing(self):
+        pattern = s.capture_if(True, 'a')
+        self.assertEqual(str(pattern), '(a)')
+        self.assertEqual(re.compile(str(pattern)).groups, 1)
+
+    def test_ranges_follow_capture_rules_when_true(self):
+        self.assertEqual(str(s.capture_if(False, s.digit())(1, 2)), r'(?:\d){1,2}')
+        with self.assertRaises(s.STRlingError):
+            s.capture_if(True, s.digit())(1, 2)
+        self.assertEqual(str(s.capture_if(True, s.digit())(3)), r'(?:(\d)(\d)(\d))')
+
+    def test_condition_must_be_bool(self):
+        with self.assertRaises(s.STRlingError):
+            s.capture_if('a', 'b')
+
+
+class TestGroupIndex(unittest.TestCase):
+    def test_counts_numbered_and_named_groups(self):
+        pattern = s.merge(s.capture('a'), s.group('x', 'b'), s.capture('c'))
+        self.assertEqual(pattern.group_index('x'), 2)
+        self.assertEqual(pattern.group_index('x'), re.compile(str(pattern)).groupindex['x'])
+
+    def test_nested_and_copied_groups(self):
+        pattern = s.merge(s.capture(s.capture('a'))(2), s.ahead(s.capture('b')), s.group('x', s.capture('c')))
+        self.assertEqual(pattern.group_index('x'), 6)
+        self.assertEqual(pattern.group_index('x'), re.compile(str(pattern)).groupindex['x'])
+
+    def test_missing_name(self):
+        self.assertIsNone(s.merge(s.capture('a')).group_index('x'))
+
+    def test_lookarounds_keep_group_names(self):
+        self.assertEqual(s.ahead(s.group('x', 'a')).named_groups, ['x'])
+        with self.assertRaises(s.STRlingError):
+            s.merge(s.ahead(s.group('x', 'a')), s.group('x', 'b'))
+
+    def test_groups_are_not_shared(self):
+        first, second = s.lit('a'), s.lit('b')
+        first.groups.append('x')
+        self.assertEqual(second.groups, [])
+
+    def test_counts_groups_re_cannot_compile(self):
+        self.assertEqual(s.merge(s.group('x', 'a'), s.not_carriage()).group_index('x'), 1)
+        self.assertEqual(s.merge(s.not_tab(), s.group('x', 'a')).group_index('x'), 1)
+
+    def test_name_must_be_str(self):
+        with self.assertRaises(s.STRlingError):
+            s.merge(s.group('x', 'a')).group_index(1)
+
+
+
+############################
+# Lookarounds
+########
+
+
+class TestLookaroundAliases(unittest.TestCase):
+    def test_aliases_match_lookarounds(self):
+        self.assertEqual(str(s.followed_by('x')), str(s.ahead('x')))
+        self.assertEqual(str(s.not_followed_by('x')), str(s.not_ahead('x')))
+        self.assertEqual(str(s.preceded_by('x')), str(s.behind('x')))
+        self.assertEqual(str(s.not_preceded_by('x')), str(s.not_behind('x')))
+
+    def test_not_followed_by_is_negative_lookahead(self):
+        pattern = s.merge(s.digit(), s.not_followed_by('x'))
+        self.assertEqual(str(pattern), r'(?:\d(?!x))')
+        self.assertEqual(re.findall(str(pattern), '1x2y'), ['2'])
+
+    def test_invalid_parameter_names_alias(self):
+        for alias in [s.followed_by, s.not_followed_by, s.preceded_by, s.not_preceded_by]:
+            with self.assertRaisesRegex(s.STRlingError, f'simply.{alias.__name__}'):
+                alias(3)
+
+
+if __name__ == '__main__':
+    unittest.main()